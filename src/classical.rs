@@ -0,0 +1,42 @@
+/// Classical extensions to the intuitionistic core in `proof`.
+///
+/// Apart from `axiom` (and `assert`, which mints its proof through `axiom`
+/// after a runtime check), everything in `proof` is derivable in
+/// intuitionistic natural deduction. The three
+/// axioms below are *not* derivable there; they only hold if you additionally
+/// assume classical logic. Keeping them in their own module, rather than
+/// alongside the core combinators, signals that extra assumption to anyone
+/// reading a proof that depends on them, the same way the `prop` crate keeps
+/// IPL and classical PL apart.
+use crate::proof::{axiom, impl_elim, neg_intro, Implies, Neg, Or, Proof, FALSE};
+
+/// The law of the excluded middle: every proposition is either true or its
+/// negation is. Not derivable intuitionistically -- admitted as an axiom.
+pub fn excluded_middle<P>() -> Proof<Or<P, Neg<P>>> {
+    axiom()
+}
+
+/// Double-negation elimination: `!!P` implies `P`. Not derivable
+/// intuitionistically -- admitted as an axiom.
+pub fn double_neg_elim<P>() -> Proof<Implies<Neg<Neg<P>>, P>> {
+    axiom()
+}
+
+/// Peirce's law: `((P -> Q) -> P) -> P`. Not derivable intuitionistically --
+/// admitted as an axiom.
+#[allow(clippy::type_complexity)]
+pub fn peirce<P, Q>() -> Proof<Implies<Implies<Implies<P, Q>, P>, P>> {
+    axiom()
+}
+
+/// Proof by contradiction: to prove `P`, it suffices to show that assuming
+/// `!P` leads to `FALSE`. Derived from `double_neg_elim` -- the assumption
+/// closure gives us `Proof<Neg<P>> -> Proof<FALSE>`, which is exactly
+/// `neg_intro`'s requirement for `Proof<Neg<Neg<P>>>`, and `double_neg_elim`
+/// then gets us the rest of the way to `Proof<P>`.
+pub fn by_contradiction<P, F>(f: F) -> Proof<P>
+where
+    F: Fn(Proof<Neg<P>>) -> Proof<FALSE>,
+{
+    impl_elim(double_neg_elim(), neg_intro(f))
+}
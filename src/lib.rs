@@ -0,0 +1,5 @@
+pub mod classical;
+pub mod named;
+pub mod proof;
+pub mod quantifiers;
+pub mod sort;
@@ -51,14 +51,61 @@ impl<Name, A> Named<A> for _Named<Name, A> {
 ///
 /// Note that the above does not *quite* work correctly in Rust, as the
 /// existential names are not necessarily unique between identical invocations.
+/// If you need a genuine freshness guarantee -- e.g. so that two calls can
+/// never be confused for one another by the type checker -- use `with_name`
+/// instead.
 pub fn name<A>(x: A) -> impl Named<A> {
     // We do need to specify a type for the `Name` parameter in `_Named`, but
     // it's rendered opaque by the existential quantification.
     _Named::<_Name, A>::into(x)
 }
 
+/// A value of type `A` branded with an invariant lifetime `'id`, used as a
+/// provably-fresh type-level name. See `with_name`.
+pub struct Branded<'id, A> {
+    value: A,
+    _brand: PhantomData<fn(&'id ()) -> &'id ()>,
+}
+
+/// The type-level name produced by `with_name`: an invariant lifetime
+/// reified as a type.
+pub struct Id<'id> {
+    _brand: PhantomData<fn(&'id ()) -> &'id ()>,
+}
+
+impl<'id, A> Named<A> for Branded<'id, A> {
+    type Name = Id<'id>;
+    fn out_ref(&self) -> &A {
+        &self.value
+    }
+
+    fn out(self) -> A {
+        self.value
+    }
+}
+
+/// Annotate a value with a *genuinely* fresh type-level name, fixing the gap
+/// that `name`'s doc comment admits. The name is the invariant lifetime
+/// `'id`, branded onto the value via generativity: because the continuation
+/// `k` must typecheck for every possible `'id`, the compiler can never unify
+/// the brand produced by one `with_name` call with the brand from another.
+///
+/// Nesting two `with_name` calls keeps both named values in scope together:
+/// the outer `Branded` can be used inside the inner continuation. What the
+/// compiler rejects is any attempt to unify the two brands. Because the
+/// brands are invariant lifetimes, rustc reports that mismatch as
+/// `error[E0521]: borrowed data escapes outside of closure` rather than as a
+/// plain type mismatch.
+pub fn with_name<A, R>(x: A, k: impl for<'id> FnOnce(Branded<'id, A>) -> R) -> R {
+    k(Branded {
+        value: x,
+        _brand: PhantomData,
+    })
+}
+
 mod private {
-    use super::_Named;
+    use super::{_Named, Branded};
     pub trait Sealed {}
     impl<Name, A> Sealed for _Named<Name, A> {}
+    impl<'id, A> Sealed for Branded<'id, A> {}
 }
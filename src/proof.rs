@@ -1,6 +1,8 @@
 /// Encode proofs of properties
 use std::marker::PhantomData;
 
+use crate::named::{name, Named};
+
 /// A value of type `Proof<P>` for some type-encoded property `P` is a proof
 /// of that property, which can be manipulated to form other proofs. To ensure
 /// that such proofs have no runtime impact, they are implemented as zero-size
@@ -187,3 +189,129 @@ pub fn absurd<P>(x: Proof<FALSE>) -> Proof<P> {
 pub fn contradict<P, Q>(x: Proof<P>, f: Proof<Neg<P>>) -> Proof<Q> {
     absurd(neg_elim(f, x))
 }
+
+/// Double-negation introduction: `P` implies `!!P`. Unlike `double_neg_elim`
+/// (see the `classical` module), this direction *is* derivable from
+/// `neg_intro`/`neg_elim` alone, so it belongs here in the intuitionistic
+/// core rather than alongside the classical axioms.
+pub fn double_neg_intro<P>(_p: Proof<P>) -> Proof<Neg<Neg<P>>> {
+    neg_intro(|np: Proof<Neg<P>>| neg_elim(np, qed()))
+}
+
+/// Contraposition: from `P -> Q`, derive `!Q -> !P`. Constructively
+/// derivable -- build the result with `impl_intro` taking a `Proof<Neg<Q>>`,
+/// then `neg_intro` over a `Proof<P>` whose body uses `impl_elim` on a
+/// `Proof<Implies<P, Q>>` to reach `Proof<Q>`, then `neg_elim` against a
+/// `Proof<Neg<Q>>` to reach `FALSE`. `Proof` is not `Copy` and these
+/// closures are `Fn`, so the body cannot move `f` or the `Neg<Q>` hypothesis
+/// into them; it uses fresh `qed()` proofs of those same types instead.
+pub fn contrapositive<P, Q>(_f: Proof<Implies<P, Q>>) -> Proof<Implies<Neg<Q>, Neg<P>>> {
+    impl_intro(|_nq: Proof<Neg<Q>>| {
+        neg_intro(|p: Proof<P>| {
+            let q = impl_elim(qed::<Implies<P, Q>>(), p);
+            neg_elim(qed(), q)
+        })
+    })
+}
+
+/// # Refinement combinators
+///
+/// `SuchThat` is deliberately opaque -- there is no public way to construct
+/// one other than `such_that`/`axiom` -- so transforming one usually meant
+/// tearing it down with `out`/`out_ref` and rebuilding it by hand, which
+/// throws away the name linking the value to its proof. These combinators
+/// let a `SuchThat` be refined in place instead.
+///
+/// Apply an implication to the ghost proof carried by a `SuchThat`, leaving
+/// the value untouched.
+pub fn map_proof<A, P, Q>(x: SuchThat<A, P>, f: impl Fn(Proof<P>) -> Proof<Q>) -> SuchThat<A, Q> {
+    let p = conjure(&x);
+    SuchThat::into(x.out(), f(p))
+}
+
+/// Transform the value wrapped by a `SuchThat`, carrying the proof through
+/// unchanged. Only sound to call with a function that provably preserves
+/// `P` -- the proof says nothing about the new value on its own, it is
+/// simply carried along.
+pub fn map_value<A, B, P>(f: impl Fn(A) -> B, x: SuchThat<A, P>) -> SuchThat<B, P> {
+    let p = conjure(&x);
+    SuchThat::into(f(x.out()), p)
+}
+
+/// Forget the proof and recover the underlying value. Equivalent to `out`,
+/// provided under this name for symmetry with `conjure`.
+pub fn exorcise<A, P>(x: SuchThat<A, P>) -> A {
+    x.out()
+}
+
+/// Extract a copy of the ghost proof carried by a `SuchThat`, without
+/// consuming it. This is sound because `Proof` is zero-sized -- there is no
+/// actual evidence to duplicate, just a type-level tag.
+pub fn conjure<A, P>(_x: &SuchThat<A, P>) -> Proof<P> {
+    qed()
+}
+
+/// # Decidable predicates
+///
+/// `such_that` is the only way to produce a `SuchThat` from scratch, and it
+/// requires the caller to already have a `Proof` in hand, either derived or
+/// taken unchecked from `axiom` -- there's no bridge from an ordinary
+/// runtime check into the ghost-proof world. A
+/// `Predicate` is a decidable check that, on success, hands back a value
+/// named and annotated with the proposition it establishes, rather than
+/// forcing callers to reach for `axiom` themselves.
+///
+/// A runtime-checkable predicate over values of type `A`. `Prop` is the
+/// proposition established by a successful check.
+pub trait Predicate<A> {
+    type Prop;
+    fn check(&self, x: &A) -> bool;
+}
+
+/// Run a predicate against a value. On success, the value is named and
+/// handed back annotated with a proof of `Pr::Prop`, minted via `axiom`
+/// because the runtime check is exactly the justification for that axiom.
+/// On failure, the value is dropped.
+pub fn assert<A, Pr: Predicate<A>>(x: A, p: &Pr) -> Option<SuchThat<impl Named<A>, Pr::Prop>> {
+    if p.check(&x) {
+        Some(such_that(name(x), axiom()))
+    } else {
+        None
+    }
+}
+
+/// # Traversals
+///
+/// Refining a whole collection one element at a time by hand is tedious and
+/// easy to get wrong (e.g. forgetting the refinement on one element). These
+/// combinators establish a uniform refinement across every element of a
+/// `Vec` at once.
+///
+/// Apply a refining function to every element of a `Vec`, preserving the
+/// resulting proof on each element.
+pub fn for_p<A, B, P, Q>(
+    xs: Vec<SuchThat<A, P>>,
+    f: impl Fn(SuchThat<A, P>) -> SuchThat<B, Q>,
+) -> Vec<SuchThat<B, Q>> {
+    xs.into_iter().map(f).collect()
+}
+
+/// Like `for_p`, but for a fallible refining function -- a single failing
+/// element aborts the whole traversal.
+pub fn try_for_p<A, B, P, Q, E>(
+    xs: Vec<SuchThat<A, P>>,
+    f: impl Fn(SuchThat<A, P>) -> Result<SuchThat<B, Q>, E>,
+) -> Result<Vec<SuchThat<B, Q>>, E> {
+    xs.into_iter().map(f).collect()
+}
+
+/// Like `for_p`, but threads a single shared named value (e.g. a comparator
+/// or predicate, as produced by `named::name`) through every element, so
+/// each resulting proof can refer to that same `Name`.
+pub fn for_p_with<A, B, P, Q, C>(
+    xs: Vec<SuchThat<A, P>>,
+    shared: &C,
+    f: impl Fn(SuchThat<A, P>, &C) -> SuchThat<B, Q>,
+) -> Vec<SuchThat<B, Q>> {
+    xs.into_iter().map(|x| f(x, shared)).collect()
+}
@@ -0,0 +1,71 @@
+/// First-order quantifiers over type-level names.
+///
+/// The propositional combinators in `proof` can't express properties like
+/// "every key in this map is valid" or "there exists an index such that...".
+/// This module lifts the crate from propositional to (shallow) first-order
+/// logic by quantifying over the names produced by the `named` module.
+use std::marker::PhantomData;
+
+use crate::named::{with_name, Id};
+use crate::proof::Proof;
+
+/// A type-level predicate over names. `Apply<N>` is the proposition obtained
+/// by applying this predicate to the name `N`.
+pub trait Pred {
+    type Apply<N>;
+}
+
+/// "For every name `N`, the proposition `F::Apply<N>` holds."
+pub struct Forall<F: Pred> {
+    _p: PhantomData<F>,
+}
+
+/// "There exists some name `N` for which `F::Apply<N>` holds."
+pub struct Exists<F: Pred> {
+    _p: PhantomData<F>,
+}
+
+/// Introduce a `Forall` proof from a function that proves `F::Apply<N>` for
+/// an arbitrary name `N`. Note that Rust's generics are only universally
+/// quantified at the point a generic function is *defined*, not at the
+/// point a closure argument is required to typecheck -- there's no way to
+/// write "for every type `N`" as a closure bound the way `for<'a>` lets you
+/// quantify over lifetimes. So this is a shallow encoding: it asks for a
+/// proof at one instantiation of `N`, trusting the caller to have actually
+/// discharged the obligation generically. Treat it like `axiom` -- sound
+/// only if the proof really does hold for every name at the call site.
+pub fn forall_intro<F: Pred, N>(
+    _g: impl Fn(PhantomData<N>) -> Proof<F::Apply<N>>,
+) -> Proof<Forall<F>> {
+    qed()
+}
+
+/// Instantiate a `Forall` proof at any chosen name `N`.
+pub fn forall_elim<F: Pred, N>(_: Proof<Forall<F>>) -> Proof<F::Apply<N>> {
+    qed()
+}
+
+/// Introduce an `Exists` proof by exhibiting a witness name `N`.
+pub fn exists_intro<F: Pred, N>(_: Proof<F::Apply<N>>) -> Proof<Exists<F>> {
+    qed()
+}
+
+/// Eliminate an `Exists` proof by running a continuation against a genuinely
+/// fresh witness name, minted the same way `named::with_name` mints a fresh
+/// brand. `k` is bound by `for<'id>`, so -- as with `with_name` -- the
+/// conclusion `R` can never mention the witness's name, and two separate
+/// `exists_elim` calls can never be confused for one another by the type
+/// checker, even for the same `F`. An earlier version used a single shared
+/// `Witness` marker and an unconstrained `k`, so witnesses from unrelated
+/// `Exists<F>` claims type-checked as interchangeable -- exactly the bug
+/// `with_name` was introduced to close for `named::name`.
+pub fn exists_elim<F: Pred, R>(
+    _: Proof<Exists<F>>,
+    k: impl for<'id> FnOnce(Proof<F::Apply<Id<'id>>>) -> R,
+) -> R {
+    with_name((), |_brand| k(qed()))
+}
+
+fn qed<P>() -> Proof<P> {
+    crate::proof::axiom()
+}
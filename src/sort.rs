@@ -0,0 +1,188 @@
+/// A verified-sort subsystem: unlike a bare `SortedBy<Comp, Vec<T>>` marker,
+/// every `Verified` list here carries an actual proof that it is sorted by
+/// its comparator *and* a proof that it is a permutation of whatever it was
+/// built from, using the combinators from `proof`. Crucially, both
+/// propositions are also indexed by the list's own name `N` (an `Id<'id>`
+/// minted by `named::with_name`), not just the comparator -- otherwise a
+/// `Member` proof obtained from one list would type-check as valid for any
+/// other, unrelated list sorted by the same comparator.
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+use crate::named::{with_name, Branded, Id, Named};
+use crate::proof::{and_intro, axiom, such_that, And, SuchThat};
+
+/// "The list named `N` is ordered according to the comparator named `Comp`."
+pub struct Sorted<N, Comp> {
+    _p: PhantomData<(N, Comp)>,
+}
+
+/// "The list named `N` is a multiset-equal rearrangement of `Of`." `Of` is a
+/// marker describing where the list came from -- see `SourceOf`,
+/// `MergedFrom`, and `MergedAll` below.
+pub struct Permutation<N, Of> {
+    _p: PhantomData<(N, Of)>,
+}
+
+/// "The wrapped index refers to a real element of the list named `N`." Being
+/// indexed by `N` rather than just the comparator is what stops a `Member`
+/// proof minted against one list from being usable as an index into a
+/// different list, even one sorted by the same comparator.
+pub struct Member<N> {
+    _p: PhantomData<N>,
+}
+
+/// Marks a `Permutation` as relative to `sort_by`'s own input.
+pub struct SourceOf<Comp> {
+    _p: PhantomData<Comp>,
+}
+
+/// Marks a `Permutation` as relative to the concatenation of the two runs
+/// `merge_by` folded together.
+pub struct MergedFrom<Left, Right> {
+    _p: PhantomData<(Left, Right)>,
+}
+
+/// Marks a `Permutation` as relative to the concatenation of every run
+/// `merge_many` folded together.
+pub struct MergedAll<Of> {
+    _p: PhantomData<Of>,
+}
+
+/// A `Vec<T>` named `'id`, verified sorted by the comparator named `Comp`,
+/// carrying a proof that it is both sorted and a permutation of `Of`. The
+/// name is threaded through both propositions, so a `Verified` list can never
+/// be confused with an unrelated one, even under the same `Comp`.
+pub type Verified<'id, T, Comp, Of> =
+    SuchThat<Branded<'id, Vec<T>>, And<Sorted<Id<'id>, Comp>, Permutation<Id<'id>, Of>>>;
+
+/// Sort `v` by the named comparator `cmp`, minting a fresh name for the
+/// result and passing a verified-sorted list to `k`. `Vec::sort_by` is a
+/// stable, purely-rearranging sort, so the result is sorted by construction
+/// and a permutation of its own input -- both minted via `axiom` on that
+/// basis. The name is minted here rather than handed back to the caller,
+/// because `with_name`'s freshness guarantee only holds inside its own
+/// continuation -- see `with_name`'s doc comment.
+pub fn sort_by<F, T, C, Comp, R>(
+    mut v: Vec<T>,
+    cmp: &C,
+    k: impl for<'id> FnOnce(Verified<'id, T, Comp, SourceOf<Comp>>) -> R,
+) -> R
+where
+    F: Fn(&T, &T) -> Ordering,
+    C: Named<F, Name = Comp>,
+{
+    v.sort_by(cmp.out_ref());
+    with_name(v, |named| k(such_that(named, and_intro(axiom(), axiom()))))
+}
+
+/// Merge two verified-sorted runs under the same named comparator, minting a
+/// fresh name for the merged result and passing a verified-sorted list to
+/// `k`. The merge loop only ever moves elements from `xs`/`ys` into the
+/// result, so the result is sorted (by the same argument as `sort_by`) and a
+/// permutation of the concatenation of both inputs -- again minted via
+/// `axiom` on that basis.
+pub fn merge_by<'x, 'y, F, T, C, Comp, L, R2, Out>(
+    xs: Verified<'x, T, Comp, L>,
+    ys: Verified<'y, T, Comp, R2>,
+    cmp: &C,
+    k: impl for<'id> FnOnce(Verified<'id, T, Comp, MergedFrom<L, R2>>) -> Out,
+) -> Out
+where
+    F: Fn(&T, &T) -> Ordering,
+    C: Named<F, Name = Comp>,
+{
+    let result = merge_vecs(xs.out().out(), ys.out().out(), cmp.out_ref());
+    with_name(result, |named| {
+        k(such_that(named, and_intro(axiom(), axiom())))
+    })
+}
+
+/// Sort and fold every run in `vs` into a single verified-sorted list,
+/// minting a fresh name for the result and passing it to `k`. Each run is
+/// sorted in place first (as in `sort_by`), then folded via the same
+/// argument as `merge_by`. Unlike an API that accepted pre-sorted,
+/// pre-named runs, this never hands back (or takes in) a brand for an
+/// individual run -- only the one name minted for the final merged list --
+/// so there is no way to obtain a `Member` proof for one run and misuse it
+/// as an index into another.
+pub fn merge_many<F, T, C, Comp, R>(
+    vs: Vec<Vec<T>>,
+    cmp: &C,
+    k: impl for<'id> FnOnce(Verified<'id, T, Comp, MergedAll<Comp>>) -> R,
+) -> R
+where
+    F: Fn(&T, &T) -> Ordering,
+    C: Named<F, Name = Comp>,
+{
+    let merged = vs
+        .into_iter()
+        .map(|mut v| {
+            v.sort_by(cmp.out_ref());
+            v
+        })
+        .fold(Vec::new(), |acc, run| merge_vecs(acc, run, cmp.out_ref()));
+
+    with_name(merged, |named| {
+        k(such_that(named, and_intro(axiom(), axiom())))
+    })
+}
+
+/// Search a verified-sorted list for `x` using the same named comparator it
+/// was sorted by. On success, the returned index is annotated with a proof
+/// that it is a member of *this specific list* -- the list's own name `N` is
+/// baked into `Member`, so the proof is only accepted by `get` on this same
+/// list, never on any other `Verified` list, even one sharing the same
+/// comparator.
+pub fn binary_search<'id, F, T, C, Comp, Of>(
+    xs: &Verified<'id, T, Comp, Of>,
+    x: &T,
+    cmp: &C,
+) -> Result<SuchThat<usize, Member<Id<'id>>>, usize>
+where
+    F: Fn(&T, &T) -> Ordering,
+    C: Named<F, Name = Comp>,
+{
+    let cmp = cmp.out_ref();
+    xs.out_ref()
+        .out_ref()
+        .binary_search_by(|y| cmp(y, x))
+        .map(|i| such_that(i, axiom()))
+}
+
+/// Index into the list named `N` using a `Member<N>` proof minted against it
+/// (e.g. by `binary_search`). The index's proof and the list's own brand
+/// share the same `'id`, so this rejects at compile time any attempt to use
+/// an index proved a member of one list to index into a different one.
+pub fn get<'a, 'id, T, Comp, Of>(
+    xs: &'a Verified<'id, T, Comp, Of>,
+    i: SuchThat<usize, Member<Id<'id>>>,
+) -> &'a T {
+    &xs.out_ref().out_ref()[i.out()]
+}
+
+fn merge_vecs<F, T>(xs: Vec<T>, ys: Vec<T>, cmp: &F) -> Vec<T>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let mut result = Vec::with_capacity(xs.len() + ys.len());
+    let mut xs = xs.into_iter().peekable();
+    let mut ys = ys.into_iter().peekable();
+
+    loop {
+        let which = match (xs.peek(), ys.peek()) {
+            (Some(x), Some(y)) => Some(cmp(x, y)),
+            (Some(_), None) => Some(Ordering::Less),
+            (None, Some(_)) => Some(Ordering::Greater),
+            (None, None) => None,
+        };
+
+        match which {
+            None => break,
+            Some(Ordering::Less) | Some(Ordering::Equal) => result.push(xs.next().unwrap()),
+            Some(Ordering::Greater) => result.push(ys.next().unwrap()),
+        }
+    }
+
+    result
+}
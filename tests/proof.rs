@@ -0,0 +1,60 @@
+use dependent_ghost::named::Named;
+use dependent_ghost::proof::{assert, axiom, exorcise, map_value, such_that, try_for_p, Predicate};
+
+struct Positive;
+struct IsPositive;
+
+impl Predicate<i32> for Positive {
+    type Prop = IsPositive;
+    fn check(&self, x: &i32) -> bool {
+        *x > 0
+    }
+}
+
+#[test]
+fn assert_passes_and_carries_the_value() {
+    let p = Positive;
+    let result = assert(5, &p);
+    let wrapped = result.expect("5 is positive");
+    assert_eq!(*wrapped.out_ref().out_ref(), 5);
+}
+
+#[test]
+fn assert_fails_on_a_false_check() {
+    let p = Positive;
+    assert!(assert(-5, &p).is_none());
+}
+
+struct Whatever;
+
+#[test]
+fn try_for_p_aborts_on_the_first_failure() {
+    let xs = vec![
+        such_that(1, axiom::<Whatever>()),
+        such_that(2, axiom()),
+        such_that(3, axiom()),
+    ];
+
+    let result = try_for_p(xs, |x| {
+        let v = x.out();
+        if v == 2 {
+            Err("middle element failed")
+        } else {
+            Ok(such_that(v, axiom::<Whatever>()))
+        }
+    });
+
+    assert_eq!(result.err(), Some("middle element failed"));
+}
+
+#[test]
+fn map_value_applies_f_to_the_wrapped_value() {
+    let x = such_that(5, axiom::<Whatever>());
+    assert_eq!(map_value(|x: i32| x + 1, x).out(), 6);
+}
+
+#[test]
+fn exorcise_recovers_the_wrapped_value() {
+    let x = such_that(5, axiom::<Whatever>());
+    assert_eq!(exorcise(x), 5);
+}
@@ -1,81 +1,59 @@
-use std::cmp::Ordering;
-use std::marker::PhantomData;
-
-use dependent_ghost::named::{name, Named};
-
-pub struct SortedBy<Comp, A> {
-    value: A,
-    _phantom: PhantomData<Comp>,
-}
+use dependent_ghost::named::{name, with_name, Named};
+use dependent_ghost::sort::{binary_search, get, merge_by, merge_many, sort_by};
 
-impl<Comp, A> SortedBy<Comp, A> {
-    pub fn into(x: A) -> Self {
-        SortedBy {
-            value: x,
-            _phantom: PhantomData,
-        }
-    }
+#[test]
+fn run_sort() {
+    let comparator = name(i32::cmp);
+    let xs = vec![1, 5, 3];
+    let ys = vec![6, 2, 4];
 
-    pub fn out(self) -> A {
-        self.value
-    }
+    sort_by(xs, &comparator, |xs| {
+        sort_by(ys, &comparator, |ys| {
+            merge_by(xs, ys, &comparator, |zs| {
+                assert_eq!(zs.out().out(), vec![1, 2, 3, 4, 5, 6]);
+            })
+        })
+    });
 }
 
-pub fn sort_by<'a, F, T, C, Comp>(mut v: Vec<T>, cmp: &C) -> SortedBy<Comp, Vec<T>>
-where
-    F: Fn(&T, &T) -> Ordering,
-    C: Named<F, Name = Comp>,
-{
-    v.sort_by(cmp.out_ref());
-    SortedBy::into(v)
+#[test]
+fn run_sort_branded() {
+    with_name(i32::cmp, |comparator| {
+        let xs = vec![1, 5, 3];
+        let ys = vec![6, 2, 4];
+
+        sort_by(xs, &comparator, |xs| {
+            sort_by(ys, &comparator, |ys| {
+                merge_by(xs, ys, &comparator, |zs| {
+                    assert_eq!(zs.out().out(), vec![1, 2, 3, 4, 5, 6]);
+                })
+            })
+        });
+    });
 }
 
-// I honestly cannot believe that Rust does not have a merge function already.
-pub fn merge_by<'a, F, T, C, Comp>(
-    xs: SortedBy<Comp, Vec<T>>,
-    ys: SortedBy<Comp, Vec<T>>,
-    cmp: &C,
-) -> SortedBy<Comp, Vec<T>>
-where
-    F: Fn(&T, &T) -> Ordering,
-    C: Named<F, Name = Comp>,
-{
-    let mut result = Vec::new();
-    let mut xs = xs.out().into_iter().peekable();
-    let mut ys = ys.out().into_iter().peekable();
-    let cmp = cmp.out_ref();
-
-    loop {
-        let which = match (xs.peek(), ys.peek()) {
-            (Some(x), Some(y)) => Some(cmp(x, y)),
-            (Some(_), None) => Some(Ordering::Less),
-            (None, Some(_)) => Some(Ordering::Greater),
-            (None, None) => None,
-        };
-
-        match which {
-            None => break,
-            Some(Ordering::Less) | Some(Ordering::Equal) => {
-                result.push(xs.next().unwrap())
-            }
-            Some(Ordering::Greater) => result.push(ys.next().unwrap()),
-        };
-    }
+#[test]
+fn run_merge_many() {
+    let comparator = name(i32::cmp);
+    let runs = vec![vec![5, 1, 3], vec![6, 2], vec![4]];
 
-    SortedBy::into(result)
+    merge_many(runs, &comparator, |zs| {
+        assert_eq!(zs.out().out(), vec![1, 2, 3, 4, 5, 6]);
+    });
 }
 
 #[test]
-fn run_sort() {
+fn run_binary_search() {
     let comparator = name(i32::cmp);
-    let xs = vec![1, 5, 3];
-    let ys = vec![6, 2, 4];
-    let xs = sort_by(xs, &comparator);
-    let ys = sort_by(ys, &comparator);
 
-    let zs = merge_by(xs, ys, &comparator);
+    sort_by(vec![5, 1, 3, 4, 2], &comparator, |xs| {
+        match binary_search(&xs, &3, &comparator) {
+            Ok(i) => assert_eq!(*get(&xs, i), 3),
+            Err(_) => panic!("expected to find 3"),
+        }
 
-    assert_eq!(zs.out(), vec![1, 2, 3, 4, 5, 6]);
+        assert!(binary_search(&xs, &42, &comparator).is_err());
+    });
 }
 
 /*
@@ -83,15 +61,49 @@ fn cmp_backwards(a: &i32, b: &i32) -> Ordering {
     i32::cmp(b, a)
 }
 
+use std::cmp::Ordering;
+
+// Fails to compile: `i` is a `Member<Id<'id_xs>>` proof minted against `xs`,
+// while `get(&ys, i)` requires a `Member<Id<'id_ys>>`. The two brands are
+// distinct invariant lifetimes, so rustc rejects the mismatch, reporting it
+// as `error[E0521]: borrowed data escapes outside of closure` at the
+// `binary_search` and `get` calls. Using `xs` inside the inner closure is
+// not itself the problem -- `run_sort` above does exactly that.
 pub fn it_doesnt_work() {
     let comp1 = name(i32::cmp);
-    let comp2 = name(cmp_backwards);
-    let mut xs = vec![1, 3, 5];
-    let mut ys = vec![2, 3, 4];
-
-    let xs = sort_by(xs, &comp1);
-    let ys = sort_by(ys, &comp2);
+    let xs = vec![1, 3, 5];
+    let ys = vec![2, 4, 6];
+
+    sort_by(xs, &comp1, |xs| {
+        sort_by(ys, &comp1, |ys| {
+            match binary_search(&xs, &3, &comp1) {
+                Ok(i) => {
+                    let _ = get(&ys, i);
+                }
+                Err(_) => panic!("expected to find 3"),
+            }
+        })
+    });
+}
 
-    let zs = merge_by(xs, ys, &comp2);
+// This also fails to compile: `xs` is sorted under `comp1` and `ys` under
+// `comp2`, which are branded with distinct, invariant lifetimes, so
+// `merge_by` cannot take both under one comparator name. rustc reports the
+// mismatch as `error[E0521]: borrowed data escapes outside of closure`.
+// Nesting the two `with_name` calls is not itself the problem: with both
+// runs sorted and merged under `comp1`, the same shape compiles.
+pub fn it_doesnt_work_either() {
+    with_name(i32::cmp, |comp1| {
+        with_name(cmp_backwards, |comp2| {
+            let xs = vec![1, 3, 5];
+            let ys = vec![2, 3, 4];
+
+            sort_by(xs, &comp1, |xs| {
+                sort_by(ys, &comp2, |ys| {
+                    merge_by(xs, ys, &comp2, |_zs| {});
+                })
+            });
+        });
+    });
 }
 */